@@ -3,6 +3,7 @@ use ndarray::*;
 use ndarray_linalg::convert::replicate;
 use ndarray_linalg::error::Result;
 use ndarray_linalg::*;
+use std::fmt;
 
 pub trait SolveDual<A: Copy> {
     /// Solves a system of linear equations `A * x = b` where `A` is `self`, `b`
@@ -27,6 +28,33 @@ pub trait SolveDual<A: Copy> {
         &self,
         b: &'a mut ArrayBase<S, Ix1>,
     ) -> Result<&'a mut ArrayBase<S, Ix1>>;
+    /// Solves a system of linear equations `A * X = B` for multiple
+    /// right-hand sides at once, where `A` is `self`, `B` is the argument,
+    /// and `X` is the successful result. The real part of `A` is
+    /// factorized only once and the factorization is reused for every
+    /// column of `B`.
+    fn solve2<S: Data<Elem = A>>(&self, b: &ArrayBase<S, Ix2>) -> Result<Array2<A>> {
+        let mut b = replicate(b);
+        self.solve_inplace2(&mut b)?;
+        Ok(b)
+    }
+    /// Solves a system of linear equations `A * X = B` for multiple
+    /// right-hand sides at once, where `A` is `self`, `B` is the argument,
+    /// and `X` is the successful result.
+    fn solve_into2<S: DataMut<Elem = A>>(
+        &self,
+        mut b: ArrayBase<S, Ix2>,
+    ) -> Result<ArrayBase<S, Ix2>> {
+        self.solve_inplace2(&mut b)?;
+        Ok(b)
+    }
+    /// Solves a system of linear equations `A * X = B` for multiple
+    /// right-hand sides at once, where `A` is `self`, `B` is the argument,
+    /// and `X` is the successful result.
+    fn solve_inplace2<'a, S: DataMut<Elem = A>>(
+        &self,
+        b: &'a mut ArrayBase<S, Ix2>,
+    ) -> Result<&'a mut ArrayBase<S, Ix2>>;
 }
 
 impl<S: Data<Elem = f64>> SolveDual<f64> for ArrayBase<S, Ix2> {
@@ -47,6 +75,32 @@ impl<S: Data<Elem = f64>> SolveDual<f64> for ArrayBase<S, Ix2> {
     ) -> Result<&'a mut ArrayBase<Sb, Ix1>> {
         <Self as Solve<f64>>::solve_inplace(self, b)
     }
+    /// Solves a system of linear equations `A * X = B` for multiple
+    /// right-hand sides at once, where `A` is `self`, `B` is the argument,
+    /// and `X` is the successful result.
+    /// ```
+    /// # use approx::assert_abs_diff_eq;
+    /// # use num_hyperdual::linalg::SolveDual;
+    /// # use ndarray::{arr2};
+    /// let a = arr2(&[[1.0, 3.0],
+    ///                [5.0, 7.0]]);
+    /// let b = arr2(&[[10.0, 1.0], [26.0, 1.0]]);
+    /// let x = a.solve_into2(b).unwrap();
+    /// assert_abs_diff_eq!(x[(0, 0)], 1.0, epsilon = 1e-12);
+    /// assert_abs_diff_eq!(x[(1, 0)], 3.0, epsilon = 1e-12);
+    /// assert_abs_diff_eq!(x[(0, 1)], -0.5, epsilon = 1e-12);
+    /// assert_abs_diff_eq!(x[(1, 1)], 0.5, epsilon = 1e-12);
+    /// ```
+    fn solve_inplace2<'a, Sb: DataMut<Elem = f64>>(
+        &self,
+        b: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>> {
+        let f = self.to_owned().factorize_into()?;
+        for mut col in b.columns_mut() {
+            f.solve_inplace(&mut col)?;
+        }
+        Ok(b)
+    }
 }
 
 impl<S: Data<Elem = Dual64>> SolveDual<Dual64> for ArrayBase<S, Ix2> {
@@ -75,6 +129,43 @@ impl<S: Data<Elem = Dual64>> SolveDual<Dual64> for ArrayBase<S, Ix2> {
             .apply(|&dx0, &dx1, b| *b = Dual64::new(dx0, dx1));
         Ok(b)
     }
+    /// Solves a system of linear equations `A * X = B` for multiple
+    /// right-hand sides at once, where `A` is `self`, `B` is the argument,
+    /// and `X` is the successful result. The real part of `A` is
+    /// factorized only once and reused for every column of `B`.
+    /// ```
+    /// # use approx::assert_abs_diff_eq;
+    /// # use num_hyperdual::Dual64;
+    /// # use num_hyperdual::linalg::SolveDual;
+    /// # use ndarray::arr2;
+    /// let a = arr2(&[[Dual64::new(1.0, 2.0), Dual64::new(3.0, 4.0)],
+    ///                [Dual64::new(5.0, 6.0), Dual64::new(7.0, 8.0)]]);
+    /// let b = arr2(&[[Dual64::new(10.0, 28.0), Dual64::new(4.0, 6.0)],
+    ///                [Dual64::new(26.0, 68.0), Dual64::new(12.0, 14.0)]]);
+    /// let x = a.solve_into2(b).unwrap();
+    /// assert_eq!(x[(0, 0)], Dual64::new(1.0, 2.0));
+    /// assert_eq!(x[(1, 0)], Dual64::new(3.0, 4.0));
+    /// assert_abs_diff_eq!(x[(0, 1)].re, 1.0, epsilon = 1e-12);
+    /// assert_abs_diff_eq!(x[(0, 1)].eps, 0.0, epsilon = 1e-12);
+    /// assert_abs_diff_eq!(x[(1, 1)].re, 1.0, epsilon = 1e-12);
+    /// assert_abs_diff_eq!(x[(1, 1)].eps, 0.0, epsilon = 1e-12);
+    /// ```
+    fn solve_inplace2<'a, Sb: DataMut<Elem = Dual64>>(
+        &self,
+        b: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>> {
+        let f = self.map(Dual64::re).factorize_into()?;
+        let s1 = self.mapv(|s| s.eps);
+        for mut col in b.columns_mut() {
+            let dx0 = f.solve_into(col.map(Dual64::re))?;
+            let dx1 = f.solve_into(col.mapv(|b| b.eps) - s1.dot(&dx0))?;
+            Zip::from(&dx0)
+                .and(&dx1)
+                .and(&mut col)
+                .apply(|&dx0, &dx1, b| *b = Dual64::new(dx0, dx1));
+        }
+        Ok(b)
+    }
 }
 
 impl<S: Data<Elem = HyperDual64>> SolveDual<HyperDual64> for ArrayBase<S, Ix2> {
@@ -119,6 +210,52 @@ impl<S: Data<Elem = HyperDual64>> SolveDual<HyperDual64> for ArrayBase<S, Ix2> {
             .apply(|&dx0, &dx1, &dx2, &dx12, b| *b = HyperDual64::new(dx0, dx1, dx2, dx12));
         Ok(b)
     }
+    /// Solves a system of linear equations `A * X = B` for multiple
+    /// right-hand sides at once, where `A` is `self`, `B` is the argument,
+    /// and `X` is the successful result. The real part of `A` is
+    /// factorized only once and reused for every column of `B`.
+    /// ```
+    /// # use approx::assert_abs_diff_eq;
+    /// # use num_hyperdual::HyperDual64;
+    /// # use num_hyperdual::linalg::SolveDual;
+    /// # use ndarray::arr2;
+    /// let a = arr2(&[[HyperDual64::new(1.0, 2.0, 3.0, 4.0), HyperDual64::new(2.0, 3.0, 4.0, 5.0)],
+    ///                [HyperDual64::new(3.0, 4.0, 5.0, 6.0), HyperDual64::new(4.0, 5.0, 6.0, 7.0)]]);
+    /// let b = arr2(&[[HyperDual64::new(5.0, 16.0, 22.0, 64.0), HyperDual64::new(3.0, 5.0, 7.0, 9.0)],
+    ///                [HyperDual64::new(11.0, 32.0, 42.0, 112.0), HyperDual64::new(7.0, 9.0, 11.0, 13.0)]]);
+    /// let x = a.solve_into2(b).unwrap();
+    /// assert_abs_diff_eq!(x[(0, 0)].re, 1.0, epsilon = 1e-14);
+    /// assert_abs_diff_eq!(x[(1, 0)].re, 2.0, epsilon = 1e-14);
+    /// assert_abs_diff_eq!(x[(0, 1)].re, 1.0, epsilon = 1e-14);
+    /// assert_abs_diff_eq!(x[(0, 1)].eps1, 0.0, epsilon = 1e-14);
+    /// assert_abs_diff_eq!(x[(0, 1)].eps2, 0.0, epsilon = 1e-14);
+    /// assert_abs_diff_eq!(x[(0, 1)].eps1eps2, 0.0, epsilon = 1e-14);
+    /// assert_abs_diff_eq!(x[(1, 1)].re, 1.0, epsilon = 1e-14);
+    /// ```
+    fn solve_inplace2<'a, Sb: DataMut<Elem = HyperDual64>>(
+        &self,
+        b: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>> {
+        let s1 = self.mapv(|s| s.eps1);
+        let s2 = self.mapv(|s| s.eps2);
+        let s12 = self.mapv(|s| s.eps1eps2);
+        let f = self.map(HyperDual64::re).factorize_into()?;
+        for mut col in b.columns_mut() {
+            let dx0 = f.solve_into(col.map(HyperDual64::re))?;
+            let dx1 = f.solve_into(col.mapv(|b| b.eps1) - s1.dot(&dx0))?;
+            let dx2 = f.solve_into(col.mapv(|b| b.eps2) - s2.dot(&dx0))?;
+            let dx12 = f.solve_into(
+                col.mapv(|b| b.eps1eps2) - s1.dot(&dx2) - s2.dot(&dx1) - s12.dot(&dx0),
+            )?;
+            Zip::from(&dx0)
+                .and(&dx1)
+                .and(&dx2)
+                .and(&dx12)
+                .and(&mut col)
+                .apply(|&dx0, &dx1, &dx2, &dx12, b| *b = HyperDual64::new(dx0, dx1, dx2, dx12));
+        }
+        Ok(b)
+    }
 }
 
 impl<S: Data<Elem = HD3_64>> SolveDual<HD3_64> for ArrayBase<S, Ix2> {
@@ -164,4 +301,755 @@ impl<S: Data<Elem = HD3_64>> SolveDual<HD3_64> for ArrayBase<S, Ix2> {
             .apply(|&dx0, &dx1, &dx2, &dx3, b| *b = HD3_64::new([dx0, dx1, dx2, dx3]));
         Ok(b)
     }
+    /// Solves a system of linear equations `A * X = B` for multiple
+    /// right-hand sides at once, where `A` is `self`, `B` is the argument,
+    /// and `X` is the successful result. The real part of `A` is
+    /// factorized only once and reused for every column of `B`.
+    /// ```
+    /// # use approx::assert_abs_diff_eq;
+    /// # use num_hyperdual::HD3_64;
+    /// # use num_hyperdual::linalg::SolveDual;
+    /// # use ndarray::arr2;
+    /// let a = arr2(&[[HD3_64::new([1.0, 2.0, 3.0, 4.0]), HD3_64::new([2.0, 3.0, 4.0, 5.0])],
+    ///                [HD3_64::new([3.0, 4.0, 5.0, 6.0]), HD3_64::new([4.0, 5.0, 6.0, 7.0])]]);
+    /// let b = arr2(&[[HD3_64::new([5.0, 16.0, 48.0, 136.0]), HD3_64::new([3.0, 5.0, 7.0, 9.0])],
+    ///                [HD3_64::new([11.0, 32.0, 88.0, 232.0]), HD3_64::new([7.0, 9.0, 11.0, 13.0])]]);
+    /// let x = a.solve_into2(b).unwrap();
+    /// assert_abs_diff_eq!(x[(0, 0)].0[0], 1.0, epsilon = 1e-14);
+    /// assert_abs_diff_eq!(x[(1, 0)].0[0], 2.0, epsilon = 1e-14);
+    /// assert_abs_diff_eq!(x[(0, 1)].0[0], 1.0, epsilon = 1e-14);
+    /// assert_abs_diff_eq!(x[(0, 1)].0[1], 0.0, epsilon = 1e-14);
+    /// assert_abs_diff_eq!(x[(0, 1)].0[2], 0.0, epsilon = 1e-14);
+    /// assert_abs_diff_eq!(x[(0, 1)].0[3], 0.0, epsilon = 1e-14);
+    /// assert_abs_diff_eq!(x[(1, 1)].0[0], 1.0, epsilon = 1e-14);
+    /// ```
+    fn solve_inplace2<'a, Sb: DataMut<Elem = HD3_64>>(
+        &self,
+        b: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>> {
+        let s1 = self.mapv(|s| s.0[1]);
+        let s2 = self.mapv(|s| s.0[2]);
+        let s3 = self.mapv(|s| s.0[3]);
+        let f = self.map(HD3_64::re).factorize_into()?;
+        for mut col in b.columns_mut() {
+            let dx0 = f.solve_into(col.map(HD3_64::re))?;
+            let dx1 = f.solve_into(col.mapv(|b| b.0[1]) - s1.dot(&dx0))?;
+            let dx2 = f.solve_into(col.mapv(|b| b.0[2]) - s2.dot(&dx0) - 2.0 * s1.dot(&dx1))?;
+            let dx3 = f.solve_into(
+                col.mapv(|b| b.0[3]) - s3.dot(&dx0) - 3.0 * s2.dot(&dx1) - 3.0 * s1.dot(&dx2),
+            )?;
+            Zip::from(&dx0)
+                .and(&dx1)
+                .and(&dx2)
+                .and(&dx3)
+                .and(&mut col)
+                .apply(|&dx0, &dx1, &dx2, &dx3, b| *b = HD3_64::new([dx0, dx1, dx2, dx3]));
+        }
+        Ok(b)
+    }
+}
+
+pub trait SolveHDual<A: Copy> {
+    /// Solves a symmetric (or Hermitian) system of linear equations `A * x = b`
+    /// where `A` is `self`, `b` is the argument, and `x` is the successful
+    /// result. The real part of `A` is Cholesky-factorized once and the
+    /// factorization is reused for every derivative component, which is
+    /// roughly twice as fast as [`SolveDual::solve`] for symmetric
+    /// positive-definite systems.
+    fn solveh<S: Data<Elem = A>>(&self, b: &ArrayBase<S, Ix1>) -> Result<Array1<A>> {
+        let mut b = replicate(b);
+        self.solveh_inplace(&mut b)?;
+        Ok(b)
+    }
+    /// Solves a symmetric (or Hermitian) system of linear equations `A * x = b`
+    /// where `A` is `self`, `b` is the argument, and `x` is the successful
+    /// result.
+    fn solveh_into<S: DataMut<Elem = A>>(
+        &self,
+        mut b: ArrayBase<S, Ix1>,
+    ) -> Result<ArrayBase<S, Ix1>> {
+        self.solveh_inplace(&mut b)?;
+        Ok(b)
+    }
+    /// Solves a symmetric (or Hermitian) system of linear equations `A * x = b`
+    /// where `A` is `self`, `b` is the argument, and `x` is the successful
+    /// result.
+    fn solveh_inplace<'a, S: DataMut<Elem = A>>(
+        &self,
+        b: &'a mut ArrayBase<S, Ix1>,
+    ) -> Result<&'a mut ArrayBase<S, Ix1>>;
+}
+
+impl<S: Data<Elem = f64>> SolveHDual<f64> for ArrayBase<S, Ix2> {
+    /// Solves a symmetric system of linear equations `A * x = b` where `A` is
+    /// `self`, `b` is the argument, and `x` is the successful result.
+    /// ```
+    /// # use approx::assert_abs_diff_eq;
+    /// # use num_hyperdual::linalg::SolveHDual;
+    /// # use ndarray::{arr1, arr2};
+    /// let a = arr2(&[[2.0, 1.0],
+    ///                [1.0, 2.0]]);
+    /// let b = arr1(&[3.0, 3.0]);
+    /// let x = a.solveh_into(b).unwrap();
+    /// assert_abs_diff_eq!(x[0], 1.0, epsilon = 1e-12);
+    /// assert_abs_diff_eq!(x[1], 1.0, epsilon = 1e-12);
+    /// ```
+    fn solveh_inplace<'a, Sb: DataMut<Elem = f64>>(
+        &self,
+        b: &'a mut ArrayBase<Sb, Ix1>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix1>> {
+        <Self as SolveH<f64>>::solveh_inplace(self, b)
+    }
+}
+
+impl<S: Data<Elem = Dual64>> SolveHDual<Dual64> for ArrayBase<S, Ix2> {
+    /// Solves a symmetric system of linear equations `A * x = b` where `A` is
+    /// `self`, `b` is the argument, and `x` is the successful result.
+    /// ```
+    /// # use approx::assert_abs_diff_eq;
+    /// # use num_hyperdual::Dual64;
+    /// # use num_hyperdual::linalg::SolveHDual;
+    /// # use ndarray::{arr1, arr2};
+    /// let a = arr2(&[[Dual64::new(2.0, 1.0), Dual64::new(1.0, 0.0)],
+    ///                [Dual64::new(1.0, 0.0), Dual64::new(2.0, 1.0)]]);
+    /// let b = arr1(&[Dual64::new(3.0, 1.0), Dual64::new(3.0, 1.0)]);
+    /// let x = a.solveh_into(b).unwrap();
+    /// assert_abs_diff_eq!(x[0].re, 1.0, epsilon = 1e-12);
+    /// assert_abs_diff_eq!(x[0].eps, 0.0, epsilon = 1e-12);
+    /// assert_abs_diff_eq!(x[1].re, 1.0, epsilon = 1e-12);
+    /// assert_abs_diff_eq!(x[1].eps, 0.0, epsilon = 1e-12);
+    /// ```
+    fn solveh_inplace<'a, Sb: DataMut<Elem = Dual64>>(
+        &self,
+        b: &'a mut ArrayBase<Sb, Ix1>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix1>> {
+        let f = self.map(Dual64::re).factorizeh_into()?;
+        let dx0 = f.solveh_into(b.map(Dual64::re))?;
+        let dx1 = f.solveh_into(b.mapv(|b| b.eps) - self.mapv(|s| s.eps).dot(&dx0))?;
+        Zip::from(&dx0)
+            .and(&dx1)
+            .and(&mut *b)
+            .apply(|&dx0, &dx1, b| *b = Dual64::new(dx0, dx1));
+        Ok(b)
+    }
+}
+
+impl<S: Data<Elem = HyperDual64>> SolveHDual<HyperDual64> for ArrayBase<S, Ix2> {
+    /// Solves a symmetric system of linear equations `A * x = b` where `A` is
+    /// `self`, `b` is the argument, and `x` is the successful result.
+    /// ```
+    /// # use approx::assert_abs_diff_eq;
+    /// # use num_hyperdual::HyperDual64;
+    /// # use num_hyperdual::linalg::SolveHDual;
+    /// # use ndarray::{arr1, arr2};
+    /// let a = arr2(&[[HyperDual64::new(2.0, 0.0, 1.0, 0.0), HyperDual64::new(1.0, 0.0, 0.0, 0.0)],
+    ///                [HyperDual64::new(1.0, 0.0, 0.0, 0.0), HyperDual64::new(2.0, 1.0, 0.0, 0.0)]]);
+    /// let b = arr1(&[HyperDual64::new(3.0, 0.0, 0.0, 0.0), HyperDual64::new(3.0, 0.0, 0.0, 0.0)]);
+    /// let x = a.solveh_into(b).unwrap();
+    /// assert_abs_diff_eq!(x[0].re, 1.0, epsilon = 1e-12);
+    /// assert_abs_diff_eq!(x[0].eps1, 1.0 / 3.0, epsilon = 1e-12);
+    /// assert_abs_diff_eq!(x[0].eps2, -2.0 / 3.0, epsilon = 1e-12);
+    /// assert_abs_diff_eq!(x[0].eps1eps2, -1.0 / 9.0, epsilon = 1e-12);
+    /// ```
+    fn solveh_inplace<'a, Sb: DataMut<Elem = HyperDual64>>(
+        &self,
+        b: &'a mut ArrayBase<Sb, Ix1>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix1>> {
+        let s1 = self.mapv(|s| s.eps1);
+        let s2 = self.mapv(|s| s.eps2);
+        let s12 = self.mapv(|s| s.eps1eps2);
+        let f = self.map(HyperDual64::re).factorizeh_into()?;
+        let dx0 = f.solveh_into(b.map(HyperDual64::re))?;
+        let dx1 = f.solveh_into(b.mapv(|b| b.eps1) - s1.dot(&dx0))?;
+        let dx2 = f.solveh_into(b.mapv(|b| b.eps2) - s2.dot(&dx0))?;
+        let dx12 =
+            f.solveh_into(b.mapv(|b| b.eps1eps2) - s1.dot(&dx2) - s2.dot(&dx1) - s12.dot(&dx0))?;
+        Zip::from(&dx0)
+            .and(&dx1)
+            .and(&dx2)
+            .and(&dx12)
+            .and(&mut *b)
+            .apply(|&dx0, &dx1, &dx2, &dx12, b| *b = HyperDual64::new(dx0, dx1, dx2, dx12));
+        Ok(b)
+    }
+}
+
+impl<S: Data<Elem = HD3_64>> SolveHDual<HD3_64> for ArrayBase<S, Ix2> {
+    /// Solves a symmetric system of linear equations `A * x = b` where `A` is
+    /// `self`, `b` is the argument, and `x` is the successful result.
+    /// ```
+    /// # use approx::assert_abs_diff_eq;
+    /// # use num_hyperdual::HD3_64;
+    /// # use num_hyperdual::linalg::SolveHDual;
+    /// # use ndarray::{arr1, arr2};
+    /// let a = arr2(&[[HD3_64::new([4.0, 1.0, 2.0, 3.0])]]);
+    /// let b = arr1(&[HD3_64::new([8.0, 5.0, 7.0, 9.0])]);
+    /// let x = a.solveh_into(b).unwrap();
+    /// assert_abs_diff_eq!(x[0].0[0], 2.0, epsilon = 1e-12);
+    /// assert_abs_diff_eq!(x[0].0[1], 0.75, epsilon = 1e-12);
+    /// assert_abs_diff_eq!(x[0].0[2], 0.375, epsilon = 1e-12);
+    /// assert_abs_diff_eq!(x[0].0[3], -0.65625, epsilon = 1e-12);
+    /// ```
+    fn solveh_inplace<'a, Sb: DataMut<Elem = HD3_64>>(
+        &self,
+        b: &'a mut ArrayBase<Sb, Ix1>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix1>> {
+        let s1 = self.mapv(|s| s.0[1]);
+        let s2 = self.mapv(|s| s.0[2]);
+        let s3 = self.mapv(|s| s.0[3]);
+        let f = self.map(HD3_64::re).factorizeh_into()?;
+        let dx0 = f.solveh_into(b.map(HD3_64::re))?;
+        let dx1 = f.solveh_into(b.mapv(|b| b.0[1]) - s1.dot(&dx0))?;
+        let dx2 = f.solveh_into(b.mapv(|b| b.0[2]) - s2.dot(&dx0) - 2.0 * s1.dot(&dx1))?;
+        let dx3 = f.solveh_into(
+            b.mapv(|b| b.0[3]) - s3.dot(&dx0) - 3.0 * s2.dot(&dx1) - 3.0 * s1.dot(&dx2),
+        )?;
+        Zip::from(&dx0)
+            .and(&dx1)
+            .and(&dx2)
+            .and(&dx3)
+            .and(&mut *b)
+            .apply(|&dx0, &dx1, &dx2, &dx3, b| *b = HD3_64::new([dx0, dx1, dx2, dx3]));
+        Ok(b)
+    }
+}
+
+/// Base tolerance for the eigenvalue gap check in [`EighDual`], scaled by
+/// [`eigenvalue_gap_tolerance`] to the magnitude of the eigenvalues being
+/// compared.
+const EIGENVALUE_GAP_TOLERANCE: f64 = 1e-10;
+
+/// Two eigenvalues of the real part of a matrix are considered degenerate
+/// (and rejected by [`EighDual`]) once their gap drops below this threshold,
+/// below which the analytic eigenvector derivative blows up. The threshold
+/// scales with the magnitude of the eigenvalues so the check is meaningful
+/// for both tiny and large matrices.
+fn eigenvalue_gap_tolerance(a: f64, b: f64) -> f64 {
+    EIGENVALUE_GAP_TOLERANCE * (1.0 + a.abs().max(b.abs()))
+}
+
+/// Error returned by [`EighDual::eigh`].
+#[derive(Debug)]
+pub enum EighError {
+    /// The real-part eigendecomposition itself failed.
+    Lapack(ndarray_linalg::error::LinalgError),
+    /// Two eigenvalues of the real part are degenerate (or nearly so), so
+    /// the analytic eigenvector derivative, which divides by their
+    /// difference, cannot be evaluated.
+    DegenerateEigenvalues { i: usize, j: usize, gap: f64 },
+}
+
+impl fmt::Display for EighError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Lapack(e) => write!(f, "{}", e),
+            Self::DegenerateEigenvalues { i, j, gap } => write!(
+                f,
+                "eigenvalues {} and {} are degenerate (gap = {:e}); cannot propagate derivatives",
+                i, j, gap
+            ),
+        }
+    }
+}
+
+impl std::error::Error for EighError {}
+
+impl From<ndarray_linalg::error::LinalgError> for EighError {
+    fn from(e: ndarray_linalg::error::LinalgError) -> Self {
+        Self::Lapack(e)
+    }
+}
+
+/// Dual-aware eigendecomposition of a symmetric matrix.
+pub trait EighDual<A: Copy> {
+    /// Computes the eigenvalues and eigenvectors of a symmetric matrix whose
+    /// entries carry dual derivatives, propagating the derivatives
+    /// analytically rather than differentiating the LAPACK call itself.
+    fn eigh(&self) -> std::result::Result<(Array1<A>, Array2<A>), EighError>;
+}
+
+impl<S: Data<Elem = Dual64>> EighDual<Dual64> for ArrayBase<S, Ix2> {
+    /// ```
+    /// # use approx::assert_abs_diff_eq;
+    /// # use num_hyperdual::Dual64;
+    /// # use num_hyperdual::linalg::EighDual;
+    /// # use ndarray::arr2;
+    /// let a = arr2(&[[Dual64::new(2.0, 1.0), Dual64::new(0.0, 0.0)],
+    ///                [Dual64::new(0.0, 0.0), Dual64::new(3.0, -1.0)]]);
+    /// let (vals, _vecs) = a.eigh().unwrap();
+    /// assert_abs_diff_eq!(vals[0].re, 2.0, epsilon = 1e-12);
+    /// assert_abs_diff_eq!(vals[0].eps, 1.0, epsilon = 1e-12);
+    /// assert_abs_diff_eq!(vals[1].re, 3.0, epsilon = 1e-12);
+    /// assert_abs_diff_eq!(vals[1].eps, -1.0, epsilon = 1e-12);
+    /// ```
+    fn eigh(&self) -> std::result::Result<(Array1<Dual64>, Array2<Dual64>), EighError> {
+        let a0 = self.map(Dual64::re);
+        let a1 = self.mapv(|s| s.eps);
+        let (vals0, vecs0) = a0.eigh(UPLO::Lower)?;
+        let n = vals0.len();
+
+        let mut vals = Array1::from_elem(n, Dual64::new(0.0, 0.0));
+        let mut vecs = Array2::from_elem((n, n), Dual64::new(0.0, 0.0));
+        let a1v = a1.dot(&vecs0);
+        for i in 0..n {
+            let vi = vecs0.column(i);
+            let dval = vi.dot(&a1v.column(i));
+            vals[i] = Dual64::new(vals0[i], dval);
+
+            let mut dvec = Array1::<f64>::zeros(n);
+            for j in 0..n {
+                if i == j {
+                    continue;
+                }
+                let gap = vals0[i] - vals0[j];
+                if gap.abs() < eigenvalue_gap_tolerance(vals0[i], vals0[j]) {
+                    return Err(EighError::DegenerateEigenvalues { i, j, gap });
+                }
+                let coeff = vecs0.column(j).dot(&a1v.column(i)) / gap;
+                dvec.scaled_add(coeff, &vecs0.column(j));
+            }
+            for k in 0..n {
+                vecs[(k, i)] = Dual64::new(vecs0[(k, i)], dvec[k]);
+            }
+        }
+        Ok((vals, vecs))
+    }
+}
+
+impl<S: Data<Elem = HyperDual64>> EighDual<HyperDual64> for ArrayBase<S, Ix2> {
+    /// ```
+    /// # use approx::assert_abs_diff_eq;
+    /// # use num_hyperdual::HyperDual64;
+    /// # use num_hyperdual::linalg::EighDual;
+    /// # use ndarray::arr2;
+    /// let a = arr2(&[[HyperDual64::new(2.0, 0.0, 0.0, 0.0), HyperDual64::new(0.0, 1.0, 1.0, 0.0)],
+    ///                [HyperDual64::new(0.0, 1.0, 1.0, 0.0), HyperDual64::new(3.0, 0.0, 0.0, 0.0)]]);
+    /// let (vals, vecs) = a.eigh().unwrap();
+    /// assert_abs_diff_eq!(vals[0].re, 2.0, epsilon = 1e-12);
+    /// assert_abs_diff_eq!(vals[0].eps1, 0.0, epsilon = 1e-12);
+    /// assert_abs_diff_eq!(vals[0].eps2, 0.0, epsilon = 1e-12);
+    /// assert_abs_diff_eq!(vals[0].eps1eps2, -2.0, epsilon = 1e-12);
+    /// assert_abs_diff_eq!(vals[1].eps1eps2, 2.0, epsilon = 1e-12);
+    /// // Off-diagonal eps1/eps2 coupling makes d1 and d2 non-orthogonal, so
+    /// // the mixed eigenvector derivative has a component along the
+    /// // eigenvector itself (fixed by the normalization constraint); this
+    /// // would stay (wrongly) zero if that component were dropped.
+    /// assert_abs_diff_eq!(vecs[(0, 0)].eps1eps2, -1.0, epsilon = 1e-12);
+    /// assert_abs_diff_eq!(vecs[(1, 0)].eps1, -1.0, epsilon = 1e-12);
+    /// assert_abs_diff_eq!(vecs[(1, 0)].eps2, -1.0, epsilon = 1e-12);
+    /// ```
+    fn eigh(&self) -> std::result::Result<(Array1<HyperDual64>, Array2<HyperDual64>), EighError> {
+        let a0 = self.map(HyperDual64::re);
+        let a1 = self.mapv(|s| s.eps1);
+        let a2 = self.mapv(|s| s.eps2);
+        let a12 = self.mapv(|s| s.eps1eps2);
+        let (vals0, vecs0) = a0.eigh(UPLO::Lower)?;
+        let n = vals0.len();
+
+        let a1v = a1.dot(&vecs0);
+        let a2v = a2.dot(&vecs0);
+        let a12v = a12.dot(&vecs0);
+
+        let mut dval1 = Array1::<f64>::zeros(n);
+        let mut dval2 = Array1::<f64>::zeros(n);
+        let mut dval12 = Array1::<f64>::zeros(n);
+        let mut dvec1 = Array2::<f64>::zeros((n, n));
+        let mut dvec2 = Array2::<f64>::zeros((n, n));
+        let mut dvec12 = Array2::<f64>::zeros((n, n));
+        for i in 0..n {
+            let vi = vecs0.column(i);
+            dval1[i] = vi.dot(&a1v.column(i));
+            dval2[i] = vi.dot(&a2v.column(i));
+
+            let mut d1 = Array1::<f64>::zeros(n);
+            let mut d2 = Array1::<f64>::zeros(n);
+            for j in 0..n {
+                if i == j {
+                    continue;
+                }
+                let gap = vals0[i] - vals0[j];
+                if gap.abs() < eigenvalue_gap_tolerance(vals0[i], vals0[j]) {
+                    return Err(EighError::DegenerateEigenvalues { i, j, gap });
+                }
+                let vj = vecs0.column(j);
+                d1.scaled_add(vj.dot(&a1v.column(i)) / gap, &vj);
+                d2.scaled_add(vj.dot(&a2v.column(i)) / gap, &vj);
+            }
+
+            // Mixed second-order term, from expanding (A - lambda*I) v = 0
+            // to order eps1*eps2: (A0-lambda0*I) dv12_i = -(A1-dlambda1_i*I)
+            // dv2_i - (A2-dlambda2_i*I) dv1_i - (A12-dlambda12_i*I) v0_i.
+            dval12[i] = vi.dot(&a12v.column(i)) + vi.dot(&a1.dot(&d2)) + vi.dot(&a2.dot(&d1));
+            let a1d2 = a1.dot(&d2);
+            let a2d1 = a2.dot(&d1);
+            let mut d12 = Array1::<f64>::zeros(n);
+            for j in 0..n {
+                if i == j {
+                    continue;
+                }
+                let gap = vals0[i] - vals0[j];
+                let vj = vecs0.column(j);
+                let num = vj.dot(&a1d2) + vj.dot(&a2d1) + vj.dot(&a12v.column(i))
+                    - dval1[i] * vj.dot(&d2)
+                    - dval2[i] * vj.dot(&d1);
+                d12.scaled_add(num / gap, &vj);
+            }
+            // The normalization constraint v^T v = 1 fixes the component of
+            // d12 along v_i itself, which the (A - lambda*I) expansion above
+            // cannot see since it only determines d12 up to a multiple of v_i.
+            d12.scaled_add(-d1.dot(&d2), &vi);
+
+            dvec1.column_mut(i).assign(&d1);
+            dvec2.column_mut(i).assign(&d2);
+            dvec12.column_mut(i).assign(&d12);
+        }
+
+        let mut vals = Array1::from_elem(n, HyperDual64::new(0.0, 0.0, 0.0, 0.0));
+        let mut vecs = Array2::from_elem((n, n), HyperDual64::new(0.0, 0.0, 0.0, 0.0));
+        for i in 0..n {
+            vals[i] = HyperDual64::new(vals0[i], dval1[i], dval2[i], dval12[i]);
+            for k in 0..n {
+                vecs[(k, i)] = HyperDual64::new(
+                    vecs0[(k, i)],
+                    dvec1[(k, i)],
+                    dvec2[(k, i)],
+                    dvec12[(k, i)],
+                );
+            }
+        }
+        Ok((vals, vecs))
+    }
+}
+
+/// Dual-aware determinant, propagating derivatives via Jacobi's formula
+/// instead of running LU factorization in dual arithmetic.
+pub trait DetDual<A: Copy> {
+    /// Computes the determinant of `self`.
+    fn det(&self) -> Result<A>;
+}
+
+impl<S: Data<Elem = f64>> DetDual<f64> for ArrayBase<S, Ix2> {
+    fn det(&self) -> Result<f64> {
+        <Self as Determinant<f64>>::det(self)
+    }
+}
+
+impl<S: Data<Elem = Dual64>> DetDual<Dual64> for ArrayBase<S, Ix2> {
+    /// Computes the determinant of a dual-valued matrix using Jacobi's
+    /// formula `d(det) = det(A0)*tr(A0^-1 A1)`, reusing a single
+    /// factorization of the real part `A0` both for `det(A0)` and for the
+    /// solves needed to form `A0^-1 A1`.
+    /// ```
+    /// # use approx::assert_abs_diff_eq;
+    /// # use num_hyperdual::Dual64;
+    /// # use num_hyperdual::linalg::DetDual;
+    /// # use ndarray::arr2;
+    /// let a = arr2(&[[Dual64::new(1.0, 1.0), Dual64::new(3.0, 0.0)],
+    ///                [Dual64::new(5.0, 0.0), Dual64::new(7.0, 0.0)]]);
+    /// let det = a.det().unwrap();
+    /// assert_abs_diff_eq!(det.re, -8.0, epsilon = 1e-12);
+    /// assert_abs_diff_eq!(det.eps, 7.0, epsilon = 1e-12);
+    /// ```
+    fn det(&self) -> Result<Dual64> {
+        let a0 = self.map(Dual64::re);
+        let a1 = self.mapv(|s| s.eps);
+        let f = a0.factorize_into()?;
+        let det0 = f.det()?;
+        let mut b1 = Array2::zeros(a1.raw_dim());
+        for (mut out, col) in b1.columns_mut().into_iter().zip(a1.columns()) {
+            out.assign(&f.solve_into(col.to_owned())?);
+        }
+        let tr1 = b1.diag().sum();
+        Ok(Dual64::new(det0, det0 * tr1))
+    }
+}
+
+impl<S: Data<Elem = HyperDual64>> DetDual<HyperDual64> for ArrayBase<S, Ix2> {
+    /// Computes the determinant of a `HyperDual64`-valued matrix, with the
+    /// mixed second-order term `det(A0)*[tr(B1)tr(B2) - tr(B1 B2) +
+    /// tr(A0^-1 A12)]` where `B1 = A0^-1 A1`, `B2 = A0^-1 A2`.
+    /// ```
+    /// # use approx::assert_abs_diff_eq;
+    /// # use num_hyperdual::HyperDual64;
+    /// # use num_hyperdual::linalg::DetDual;
+    /// # use ndarray::arr2;
+    /// let a = arr2(&[[HyperDual64::new(1.0, 1.0, 0.0, 0.0), HyperDual64::new(3.0, 0.0, 0.0, 0.0)],
+    ///                [HyperDual64::new(5.0, 0.0, 0.0, 0.0), HyperDual64::new(7.0, 0.0, 1.0, 0.0)]]);
+    /// let det = a.det().unwrap();
+    /// assert_abs_diff_eq!(det.re, -8.0, epsilon = 1e-12);
+    /// assert_abs_diff_eq!(det.eps1, 7.0, epsilon = 1e-12);
+    /// assert_abs_diff_eq!(det.eps2, 1.0, epsilon = 1e-12);
+    /// assert_abs_diff_eq!(det.eps1eps2, 1.0, epsilon = 1e-12);
+    /// ```
+    fn det(&self) -> Result<HyperDual64> {
+        let a0 = self.map(HyperDual64::re);
+        let a1 = self.mapv(|s| s.eps1);
+        let a2 = self.mapv(|s| s.eps2);
+        let a12 = self.mapv(|s| s.eps1eps2);
+        let f = a0.factorize_into()?;
+        let det0 = f.det()?;
+
+        let solve_cols = |m: &Array2<f64>| -> Result<Array2<f64>> {
+            let mut out = Array2::zeros(m.raw_dim());
+            for (mut out_col, col) in out.columns_mut().into_iter().zip(m.columns()) {
+                out_col.assign(&f.solve_into(col.to_owned())?);
+            }
+            Ok(out)
+        };
+        let b1 = solve_cols(&a1)?;
+        let b2 = solve_cols(&a2)?;
+        let b12 = solve_cols(&a12)?;
+
+        let tr1 = b1.diag().sum();
+        let tr2 = b2.diag().sum();
+        let tr12 = b12.diag().sum();
+        let tr_b1b2 = b1.dot(&b2).diag().sum();
+
+        let d1 = det0 * tr1;
+        let d2 = det0 * tr2;
+        let d12 = det0 * (tr1 * tr2 - tr_b1b2 + tr12);
+        Ok(HyperDual64::new(det0, d1, d2, d12))
+    }
+}
+
+/// Dual-aware least-squares solver for overdetermined systems `A * x = b`.
+pub trait LeastSquaresDual<A: Copy> {
+    /// Solves `min ||A * x - b||_2` for a (possibly tall) dual-valued `A`,
+    /// returning the dual-valued minimizer.
+    fn least_squares<S: Data<Elem = A>>(&self, b: &ArrayBase<S, Ix1>) -> Result<Array1<A>>;
+}
+
+impl<S: Data<Elem = f64>> LeastSquaresDual<f64> for ArrayBase<S, Ix2> {
+    fn least_squares<Sb: Data<Elem = f64>>(&self, b: &ArrayBase<Sb, Ix1>) -> Result<Array1<f64>> {
+        Ok(self.to_owned().least_squares(&b.to_owned())?.solution)
+    }
+}
+
+impl<S: Data<Elem = Dual64>> LeastSquaresDual<Dual64> for ArrayBase<S, Ix2> {
+    /// Solves `min ||A * x - b||_2` for a dual-valued `A`, propagating the
+    /// derivative through the normal-equation optimality condition
+    /// `A0^T A0 x1 = A0^T b1 + A1^T b0 - (A0^T A1 + A1^T A0) x0`, reusing a
+    /// single factorization of `A0^T A0`.
+    /// ```
+    /// # use approx::assert_abs_diff_eq;
+    /// # use num_hyperdual::Dual64;
+    /// # use num_hyperdual::linalg::LeastSquaresDual;
+    /// # use ndarray::{arr1, arr2};
+    /// let a = arr2(&[[Dual64::new(1.0, 0.0)],
+    ///                [Dual64::new(2.0, 1.0)],
+    ///                [Dual64::new(3.0, 0.0)]]);
+    /// let b = arr1(&[Dual64::new(1.0, 0.0), Dual64::new(2.0, 0.0), Dual64::new(3.0, 0.0)]);
+    /// let x = a.least_squares(&b).unwrap();
+    /// assert_abs_diff_eq!(x[0].re, 1.0, epsilon = 1e-12);
+    /// assert_abs_diff_eq!(x[0].eps, -1.0 / 7.0, epsilon = 1e-12);
+    /// ```
+    fn least_squares<Sb: Data<Elem = Dual64>>(
+        &self,
+        b: &ArrayBase<Sb, Ix1>,
+    ) -> Result<Array1<Dual64>> {
+        let a0 = self.map(Dual64::re);
+        let a1 = self.mapv(|s| s.eps);
+        let b0 = b.map(Dual64::re);
+        let b1 = b.mapv(|v| v.eps);
+
+        let x0 = a0.least_squares(&b0)?.solution;
+        let f = a0.t().dot(&a0).factorize_into()?;
+
+        let rhs1 =
+            a0.t().dot(&b1) + a1.t().dot(&b0) - (a0.t().dot(&a1) + a1.t().dot(&a0)).dot(&x0);
+        let x1 = f.solve_into(rhs1)?;
+
+        Ok(Zip::from(&x0)
+            .and(&x1)
+            .map_collect(|&x0, &x1| Dual64::new(x0, x1)))
+    }
+}
+
+impl<S: Data<Elem = HyperDual64>> LeastSquaresDual<HyperDual64> for ArrayBase<S, Ix2> {
+    /// Solves `min ||A * x - b||_2` for a `HyperDual64`-valued `A`, applying
+    /// the product rule a second time to the normal equations to obtain the
+    /// mixed second-order right-hand side.
+    /// ```
+    /// # use approx::assert_abs_diff_eq;
+    /// # use num_hyperdual::HyperDual64;
+    /// # use num_hyperdual::linalg::LeastSquaresDual;
+    /// # use ndarray::{arr1, arr2};
+    /// let a = arr2(&[[HyperDual64::new(1.0, 0.0, 1.0, 0.0)],
+    ///                [HyperDual64::new(2.0, 1.0, 0.0, 0.0)],
+    ///                [HyperDual64::new(3.0, 0.0, 0.0, 0.0)]]);
+    /// let b = arr1(&[HyperDual64::new(1.0, 0.0, 0.0, 0.0),
+    ///                HyperDual64::new(2.0, 0.0, 0.0, 0.0),
+    ///                HyperDual64::new(3.0, 0.0, 0.0, 0.0)]);
+    /// let x = a.least_squares(&b).unwrap();
+    /// assert_abs_diff_eq!(x[0].re, 1.0, epsilon = 1e-12);
+    /// assert_abs_diff_eq!(x[0].eps1, -1.0 / 7.0, epsilon = 1e-12);
+    /// assert_abs_diff_eq!(x[0].eps2, -1.0 / 14.0, epsilon = 1e-12);
+    /// assert_abs_diff_eq!(x[0].eps1eps2, 2.0 / 49.0, epsilon = 1e-12);
+    /// ```
+    fn least_squares<Sb: Data<Elem = HyperDual64>>(
+        &self,
+        b: &ArrayBase<Sb, Ix1>,
+    ) -> Result<Array1<HyperDual64>> {
+        let a0 = self.map(HyperDual64::re);
+        let a1 = self.mapv(|s| s.eps1);
+        let a2 = self.mapv(|s| s.eps2);
+        let a12 = self.mapv(|s| s.eps1eps2);
+        let b0 = b.map(HyperDual64::re);
+        let b1 = b.mapv(|v| v.eps1);
+        let b2 = b.mapv(|v| v.eps2);
+        let b12 = b.mapv(|v| v.eps1eps2);
+
+        let x0 = a0.least_squares(&b0)?.solution;
+        let f = a0.t().dot(&a0).factorize_into()?;
+
+        let rhs1 =
+            a0.t().dot(&b1) + a1.t().dot(&b0) - (a0.t().dot(&a1) + a1.t().dot(&a0)).dot(&x0);
+        let x1 = f.solve_into(rhs1)?;
+
+        let rhs2 =
+            a0.t().dot(&b2) + a2.t().dot(&b0) - (a0.t().dot(&a2) + a2.t().dot(&a0)).dot(&x0);
+        let x2 = f.solve_into(rhs2)?;
+
+        let rhs12 = a12.t().dot(&b0) + a0.t().dot(&b12) + a1.t().dot(&b2) + a2.t().dot(&b1)
+            - (a1.t().dot(&a0) + a0.t().dot(&a1)).dot(&x2)
+            - (a2.t().dot(&a0) + a0.t().dot(&a2)).dot(&x1)
+            - (a12.t().dot(&a0) + a0.t().dot(&a12) + a1.t().dot(&a2) + a2.t().dot(&a1)).dot(&x0);
+        let x12 = f.solve_into(rhs12)?;
+
+        Ok(Zip::from(&x0)
+            .and(&x1)
+            .and(&x2)
+            .and(&x12)
+            .map_collect(|&x0, &x1, &x2, &x12| HyperDual64::new(x0, x1, x2, x12)))
+    }
+}
+
+impl<S: Data<Elem = HD3_64>> LeastSquaresDual<HD3_64> for ArrayBase<S, Ix2> {
+    /// Solves `min ||A * x - b||_2` for an `HD3_64`-valued `A`, applying
+    /// Leibniz's rule to the normal equations `A^T A x = A^T b` to obtain
+    /// the right-hand side at each derivative order, reusing a single
+    /// factorization of `A0^T A0`.
+    /// ```
+    /// # use approx::assert_abs_diff_eq;
+    /// # use num_hyperdual::HD3_64;
+    /// # use num_hyperdual::linalg::LeastSquaresDual;
+    /// # use ndarray::{arr1, arr2};
+    /// let a = arr2(&[[HD3_64::new([1.0, 0.0, 1.0, 0.0])],
+    ///                [HD3_64::new([2.0, 1.0, 0.0, 0.0])],
+    ///                [HD3_64::new([3.0, 0.0, 0.0, 1.0])]]);
+    /// let b = arr1(&[HD3_64::new([1.0, 0.0, 0.0, 0.0]),
+    ///                HD3_64::new([2.0, 0.0, 0.0, 0.0]),
+    ///                HD3_64::new([3.0, 0.0, 0.0, 0.0])]);
+    /// let x = a.least_squares(&b).unwrap();
+    /// assert_abs_diff_eq!(x[0].0[0], 1.0, epsilon = 1e-12);
+    /// assert_abs_diff_eq!(x[0].0[1], -1.0 / 7.0, epsilon = 1e-12);
+    /// assert_abs_diff_eq!(x[0].0[2], -13.0 / 98.0, epsilon = 1e-12);
+    /// assert_abs_diff_eq!(x[0].0[3], 15.0 / 686.0, epsilon = 1e-12);
+    /// ```
+    fn least_squares<Sb: Data<Elem = HD3_64>>(
+        &self,
+        b: &ArrayBase<Sb, Ix1>,
+    ) -> Result<Array1<HD3_64>> {
+        let a0 = self.map(HD3_64::re);
+        let a1 = self.mapv(|s| s.0[1]);
+        let a2 = self.mapv(|s| s.0[2]);
+        let a3 = self.mapv(|s| s.0[3]);
+        let b0 = b.map(HD3_64::re);
+        let b1 = b.mapv(|v| v.0[1]);
+        let b2 = b.mapv(|v| v.0[2]);
+        let b3 = b.mapv(|v| v.0[3]);
+
+        let x0 = a0.least_squares(&b0)?.solution;
+        let f = a0.t().dot(&a0).factorize_into()?;
+
+        let n1 = a1.t().dot(&a0) + a0.t().dot(&a1);
+        let n2 = a0.t().dot(&a2) + 2.0 * a1.t().dot(&a1) + a2.t().dot(&a0);
+        let n3 = a0.t().dot(&a3) + 3.0 * a1.t().dot(&a2) + 3.0 * a2.t().dot(&a1) + a3.t().dot(&a0);
+
+        let r1 = a1.t().dot(&b0) + a0.t().dot(&b1);
+        let x1 = f.solve_into(r1 - n1.dot(&x0))?;
+
+        let r2 = a2.t().dot(&b0) + 2.0 * a1.t().dot(&b1) + a0.t().dot(&b2);
+        let x2 = f.solve_into(r2 - 2.0 * n1.dot(&x1) - n2.dot(&x0))?;
+
+        let r3 = a3.t().dot(&b0) + 3.0 * a2.t().dot(&b1) + 3.0 * a1.t().dot(&b2) + a0.t().dot(&b3);
+        let x3 = f.solve_into(r3 - 3.0 * n1.dot(&x2) - 3.0 * n2.dot(&x1) - n3.dot(&x0))?;
+
+        Ok(Zip::from(&x0)
+            .and(&x1)
+            .and(&x2)
+            .and(&x3)
+            .map_collect(|&x0, &x1, &x2, &x3| HD3_64::new([x0, x1, x2, x3])))
+    }
+}
+
+impl<S: Data<Elem = DualVec64>> SolveDual<DualVec64> for ArrayBase<S, Ix2> {
+    /// Solves a system of linear equations `A * x = b` where the entries of
+    /// `A` and `b` carry an arbitrary number `N` of simultaneous first-order
+    /// derivative directions (e.g. sensitivities with respect to many
+    /// parameters). The real part `A0` is factorized only once and the
+    /// factorization is reused for the real solve and for every one of the
+    /// `N` derivative directions, replacing `N` independent `Dual64` solves
+    /// with one factorization plus `N` cheap back-substitutions.
+    /// ```
+    /// # use num_hyperdual::DualVec64;
+    /// # use num_hyperdual::linalg::SolveDual;
+    /// # use ndarray::{arr1, arr2, Array1};
+    /// let a = arr2(&[[DualVec64::new(1.0, Array1::from(vec![2.0, 0.0])),
+    ///                 DualVec64::new(3.0, Array1::from(vec![4.0, 0.0]))],
+    ///                [DualVec64::new(5.0, Array1::from(vec![6.0, 0.0])),
+    ///                 DualVec64::new(7.0, Array1::from(vec![8.0, 0.0]))]]);
+    /// let b = arr1(&[DualVec64::new(10.0, Array1::from(vec![28.0, 0.0])),
+    ///                DualVec64::new(26.0, Array1::from(vec![68.0, 0.0]))]);
+    /// let x = a.solve_into(b).unwrap();
+    /// assert_eq!(x[0].re, 1.0);
+    /// assert_eq!(x[1].re, 3.0);
+    /// assert_eq!(x[0].eps, Array1::from(vec![2.0, 0.0]));
+    /// assert_eq!(x[1].eps, Array1::from(vec![4.0, 0.0]));
+    /// ```
+    fn solve_inplace<'a, Sb: DataMut<Elem = DualVec64>>(
+        &self,
+        b: &'a mut ArrayBase<Sb, Ix1>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix1>> {
+        let f = self.map(DualVec64::re).factorize_into()?;
+        let dx0 = f.solve_into(b.map(DualVec64::re))?;
+        let n = b[0].eps.len();
+        let mut dxk = Vec::with_capacity(n);
+        for k in 0..n {
+            let ak = self.mapv(|s| s.eps[k]);
+            let bk = b.mapv(|b| b.eps[k]);
+            dxk.push(f.solve_into(bk - ak.dot(&dx0))?);
+        }
+        Zip::indexed(&dx0).and(&mut *b).apply(|i, &dx0, b| {
+            let eps = Array1::from_iter(dxk.iter().map(|dxk| dxk[i]));
+            *b = DualVec64::new(dx0, eps);
+        });
+        Ok(b)
+    }
+    /// Solves a system of linear equations `A * X = B` for multiple
+    /// right-hand sides at once, where `A` is `self`, `B` is the argument,
+    /// and `X` is the successful result. The real part of `A` is
+    /// factorized only once and reused for every column of `B` and for
+    /// every one of the `N` derivative directions.
+    fn solve_inplace2<'a, Sb: DataMut<Elem = DualVec64>>(
+        &self,
+        b: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>> {
+        let n = b[(0, 0)].eps.len();
+        let ak: Vec<_> = (0..n).map(|k| self.mapv(|s| s.eps[k])).collect();
+        let f = self.map(DualVec64::re).factorize_into()?;
+        for mut col in b.columns_mut() {
+            let dx0 = f.solve_into(col.map(DualVec64::re))?;
+            let mut dxk = Vec::with_capacity(n);
+            for k in 0..n {
+                let bk = col.mapv(|b| b.eps[k]);
+                dxk.push(f.solve_into(bk - ak[k].dot(&dx0))?);
+            }
+            Zip::indexed(&dx0).and(&mut col).apply(|i, &dx0, b| {
+                let eps = Array1::from_iter(dxk.iter().map(|dxk| dxk[i]));
+                *b = DualVec64::new(dx0, eps);
+            });
+        }
+        Ok(b)
+    }
 }
\ No newline at end of file